@@ -1,10 +1,12 @@
-use ruff_python_ast::Stmt;
+use std::collections::BTreeSet;
+
+use ruff_python_ast::{Alias, Stmt};
 use ruff_python_semantic::Binding;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
-use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_diagnostics::{Diagnostic, Edit, Fix, Violation};
 use ruff_macros::{derive_message_formats, violation};
-use ruff_text_size::Ranged;
+use ruff_text_size::{Ranged, TextSize};
 
 use crate::checkers::ast::Checker;
 
@@ -18,7 +20,8 @@ use crate::checkers::ast::Checker;
 ///
 /// For example, it's common to import `pandas` as `pd`, and then access
 /// members like `Series` via `pd.Series`, rather than importing `Series`
-/// directly.
+/// directly. This also applies to wildcard imports (`from pandas import
+/// *`), which are expanded to the explicit set of names used from them.
 ///
 /// ## Example
 /// ```python
@@ -32,8 +35,18 @@ use crate::checkers::ast::Checker;
 /// pd.Series
 /// ```
 ///
+/// ## Fix safety
+/// The fix replaces the `from` import with a module import (aliased via
+/// `lint.flake8-import-conventions.aliases`, if one is configured) and
+/// rewrites every in-scope reference to the imported members accordingly.
+/// The fix is only available when every imported member can be resolved to
+/// its usages; it is omitted if a member is re-exported (e.g. via `__all__`)
+/// or shadowed before it's used, since rewriting those references could
+/// change the meaning of the program.
+///
 /// ## Options
 /// - `lint.flake8-import-conventions.banned-from`
+/// - `lint.flake8-import-conventions.aliases`
 #[violation]
 pub struct BannedImportFrom {
     name: String,
@@ -69,22 +82,319 @@ pub(crate) fn banned_import_from_deferred(
     checker: &Checker,
     binding: &Binding,
     banned_conventions: &FxHashSet<String>,
+    aliases: &FxHashMap<String, String>,
 ) -> Option<Diagnostic> {
     let import = binding.as_any_import()?;
     let from_import = import.as_from_import()?;
 
     let qualified_name = from_import.qualified_name.to_string();
-    for banned in banned_conventions {
-        if qualified_name.starts_with(banned) {
-            let range = binding.statement(checker.semantic())?.range();
-            let diagnostic = Diagnostic::new(
-                BannedImportFrom {
-                    name: qualified_name,
-                },
-                range,
-            );
-            return Some(diagnostic);
+    let banned = banned_module(&qualified_name, banned_conventions)?;
+    let stmt = binding.statement(checker.semantic())?;
+    let mut diagnostic = Diagnostic::new(
+        BannedImportFrom {
+            name: qualified_name,
+        },
+        stmt.range(),
+    );
+    if let Some(fix) = fix_banned_import_from(checker, stmt, binding, banned, aliases) {
+        diagnostic.set_fix(fix);
+    }
+    Some(diagnostic)
+}
+
+/// Returns the banned convention that `qualified_name` falls under, if any.
+///
+/// A name is banned if it's an exact match for, or a submodule of, one of
+/// `banned_conventions` (e.g. banning `pandas` also bans `pandas.io`), so
+/// that member imports and wildcard imports are held to the same standard
+/// regardless of which submodule they're imported from.
+fn banned_module<'a>(
+    qualified_name: &str,
+    banned_conventions: &'a FxHashSet<String>,
+) -> Option<&'a String> {
+    banned_conventions.iter().find(|banned| {
+        qualified_name == banned.as_str()
+            || qualified_name
+                .strip_prefix(banned.as_str())
+                .is_some_and(|rest| rest.starts_with('.'))
+    })
+}
+
+/// ICN003
+pub(crate) fn banned_import_from_star(
+    checker: &Checker,
+    stmt: &Stmt,
+    alias: &Alias,
+    module: &str,
+    banned_conventions: &FxHashSet<String>,
+    aliases: &FxHashMap<String, String>,
+) -> Option<Diagnostic> {
+    banned_module(module, banned_conventions)?;
+
+    let mut diagnostic = Diagnostic::new(
+        BannedImportFrom {
+            name: module.to_string(),
+        },
+        stmt.range(),
+    );
+
+    if let Some(fix) = fix_banned_import_from_star(checker, stmt, alias, module, aliases) {
+        diagnostic.set_fix(fix);
+    }
+
+    Some(diagnostic)
+}
+
+/// Attempt to expand a wildcard import from a banned module into the
+/// explicit set of names actually used from it, or, if the module is
+/// alias-configured, into a qualified module import.
+///
+/// Bails out (leaving the diagnostic suggestion-only) for `__init__.py`
+/// files, since a wildcard import there is commonly used to re-export
+/// names rather than to consume them locally.
+fn fix_banned_import_from_star(
+    checker: &Checker,
+    stmt: &Stmt,
+    alias: &Alias,
+    module: &str,
+    aliases: &FxHashMap<String, String>,
+) -> Option<Fix> {
+    if checker
+        .path()
+        .file_name()
+        .is_some_and(|name| name == "__init__.py")
+    {
+        return None;
+    }
+
+    // A scope can hold more than one wildcard import (e.g. two unrelated
+    // `from x import *` statements). An unresolved reference only belongs
+    // to *this* one if it's the nearest wildcard import preceding the
+    // reference, mirroring how a later star import shadows an earlier
+    // one's names at runtime; otherwise we'd credit this import with
+    // usages that actually belong to a sibling star import and wrongly
+    // treat it as used.
+    let owner = |position: TextSize| -> Option<TextSize> {
+        preceding_star_import_starts(checker)
+            .into_iter()
+            .filter(|start| *start <= position)
+            .max()
+    };
+    let this_import = stmt.start();
+
+    // Collect every name in scope that only resolves because of this
+    // wildcard import, sorted and deduplicated.
+    let used_names: BTreeSet<&str> = checker
+        .semantic()
+        .unresolved_references()
+        .filter(|reference| reference.is_wildcard_import())
+        .filter(|reference| owner(reference.start()) == Some(this_import))
+        .map(|reference| reference.name(checker.locator()))
+        .collect();
+
+    if used_names.is_empty() {
+        // None of the names the star import could provide are actually
+        // used, so the whole import can be removed.
+        return Some(Fix::safe_edit(Edit::deletion(stmt.start(), stmt.end())));
+    }
+
+    if let Some(module_alias) = aliases.get(module) {
+        let mut edits = Vec::new();
+        for name in &used_names {
+            // Unresolved references don't carry a stable binding, so we
+            // rewrite every unresolved reference to the name that this
+            // import owns to the qualified form.
+            for reference in checker
+                .semantic()
+                .unresolved_references()
+                .filter(|reference| reference.is_wildcard_import())
+                .filter(|reference| owner(reference.start()) == Some(this_import))
+                .filter(|reference| reference.name(checker.locator()) == *name)
+            {
+                edits.push(Edit::range_replacement(
+                    format!("{module_alias}.{name}"),
+                    reference.range(),
+                ));
+            }
         }
+
+        let import_edit = match module_alias_state(checker, module, module_alias) {
+            ModuleAliasState::Compatible => Edit::deletion(stmt.start(), stmt.end()),
+            ModuleAliasState::Conflicting => return None,
+            ModuleAliasState::Unbound => {
+                Edit::range_replacement(format!("import {module} as {module_alias}"), stmt.range())
+            }
+        };
+        edits.push(import_edit);
+
+        let (first, rest) = edits.split_first()?;
+        return Some(Fix::safe_edits(first.clone(), rest.to_vec()));
+    }
+
+    let names = used_names.into_iter().collect::<Vec<_>>().join(", ");
+    Some(Fix::safe_edit(Edit::range_replacement(names, alias.range())))
+}
+
+/// Attempt to rewrite a single member import from a banned module into a
+/// qualified access on the module itself, e.g. rewrite `from pandas import
+/// Series` (plus every usage of `Series`) into `import pandas as pd` and
+/// `pd.Series`.
+///
+/// Returns `None` (leaving the diagnostic suggestion-only) if the member is
+/// re-exported via `__all__`, if its local name is rebound later in scope,
+/// or if the module alias is already bound to something unrelated.
+fn fix_banned_import_from(
+    checker: &Checker,
+    stmt: &Stmt,
+    binding: &Binding,
+    module: &str,
+    aliases: &FxHashMap<String, String>,
+) -> Option<Fix> {
+    // Re-exported members remain part of the public API; rewriting the
+    // import would silently change what the module exports.
+    if binding.is_explicit_export() {
+        return None;
+    }
+
+    // A member that's shadowed by a later binding of the same name may still
+    // be relied on through usages that resolve to that other binding.
+    if checker
+        .semantic()
+        .lookup_symbol(binding.name(checker.locator()))
+        .is_some_and(|id| checker.semantic().binding(id).range() != binding.range())
+    {
+        return None;
+    }
+
+    // `binding.name()` returns the *local* name, which is the `asname` when
+    // one is present (e.g. `Series as S` binds `S`). We need the original
+    // member name that actually lives on the module (`Series`), which is
+    // the last segment of the binding's qualified name.
+    let from_import = binding.as_any_import()?.as_from_import()?;
+    let member = from_import.qualified_name.to_string();
+    let member = member.rsplit('.').next()?.to_string();
+    let module_alias = aliases
+        .get(module)
+        .map(String::as_str)
+        .unwrap_or(module)
+        .to_string();
+
+    let mut edits = Vec::new();
+
+    // Replace every usage of the bound name with `<alias>.<member>`.
+    for reference_id in binding.references() {
+        let reference = checker.semantic().reference(reference_id);
+        edits.push(Edit::range_replacement(
+            format!("{module_alias}.{member}"),
+            reference.range(),
+        ));
+    }
+
+    // If a compatible `import <module> as <alias>` already exists, merge
+    // into it rather than inserting a duplicate; if the alias is already
+    // bound to something unrelated, bail out entirely rather than risk
+    // shadowing it; otherwise, replace the `from` import with the module
+    // import directly.
+    let import_edit = match module_alias_state(checker, module, &module_alias) {
+        ModuleAliasState::Compatible => Edit::deletion(stmt.start(), stmt.end()),
+        ModuleAliasState::Conflicting => return None,
+        ModuleAliasState::Unbound if module_alias == module => {
+            Edit::range_replacement(format!("import {module}"), stmt.range())
+        }
+        ModuleAliasState::Unbound => {
+            Edit::range_replacement(format!("import {module} as {module_alias}"), stmt.range())
+        }
+    };
+    edits.push(import_edit);
+
+    let (first, rest) = edits.split_first()?;
+    Some(Fix::safe_edits(first.clone(), rest.to_vec()))
+}
+
+/// What, if anything, the candidate module alias (e.g. `pd`) is already
+/// bound to in scope.
+enum ModuleAliasState {
+    /// Nothing is bound to the alias yet; it's safe to insert a new import.
+    Unbound,
+    /// The alias already refers to a compatible `import <module> as <alias>`
+    /// (or, when `alias == module`, a bare `import <module>`); merge into it.
+    Compatible,
+    /// The alias is already bound to something else (a variable, function,
+    /// or unrelated import); inserting a new import would shadow it.
+    Conflicting,
+}
+
+/// Returns the start position of every wildcard import bound in the current
+/// scope, most recent first, by walking the chain of bindings shadowed by
+/// the scope's `*` entry.
+fn preceding_star_import_starts(checker: &Checker) -> Vec<TextSize> {
+    let mut starts = Vec::new();
+    let mut binding_id = checker.semantic().scope().get("*");
+    while let Some(id) = binding_id {
+        let binding = checker.semantic().binding(id);
+        starts.push(binding.start());
+        binding_id = binding.shadowed_id();
+    }
+    starts
+}
+
+fn module_alias_state(checker: &Checker, module: &str, alias: &str) -> ModuleAliasState {
+    let Some(existing) = checker.semantic().lookup_symbol(alias) else {
+        return ModuleAliasState::Unbound;
+    };
+    match checker.semantic().binding(existing).as_any_import() {
+        Some(import) if import.qualified_name().to_string() == module => {
+            ModuleAliasState::Compatible
+        }
+        _ => ModuleAliasState::Conflicting,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+    use rustc_hash::{FxHashMap, FxHashSet};
+
+    use crate::assert_messages;
+    use crate::registry::Rule;
+    use crate::rules::flake8_import_conventions::settings::Settings as Flake8ImportConventionsSettings;
+    use crate::settings::LinterSettings;
+    use crate::test::test_path;
+
+    #[test]
+    fn banned_import_from() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("flake8_import_conventions/ICN003.py"),
+            &LinterSettings {
+                flake8_import_conventions: Flake8ImportConventionsSettings {
+                    aliases: FxHashMap::from_iter([("pandas".to_string(), "pd".to_string())]),
+                    banned_from: FxHashSet::from_iter(["pandas".to_string()]),
+                    ..Flake8ImportConventionsSettings::default()
+                },
+                ..LinterSettings::for_rule(Rule::BannedImportFrom)
+            },
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn banned_import_from_star_init() -> Result<()> {
+        // A wildcard import in `__init__.py` is commonly a re-export; the
+        // diagnostic should still fire, but without a fix attached.
+        let diagnostics = test_path(
+            Path::new("flake8_import_conventions/ICN003_init/__init__.py"),
+            &LinterSettings {
+                flake8_import_conventions: Flake8ImportConventionsSettings {
+                    banned_from: FxHashSet::from_iter(["pandas".to_string()]),
+                    ..Flake8ImportConventionsSettings::default()
+                },
+                ..LinterSettings::for_rule(Rule::BannedImportFrom)
+            },
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
     }
-    None
 }
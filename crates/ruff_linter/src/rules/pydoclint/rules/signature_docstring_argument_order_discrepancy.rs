@@ -1,28 +1,277 @@
-use ruff_diagnostics::Violation;
+use ruff_diagnostics::{Diagnostic, Violation};
 use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::StmtFunctionDef;
+use ruff_text_size::Ranged;
 
 use crate::checkers::ast::Checker;
+use crate::docstrings::Docstring;
 
 /// ## What it does
+/// Checks for functions whose docstring documents their parameters in a
+/// different order than they appear in the signature.
 ///
 /// ## Why is this bad?
+/// A docstring that lists parameters out of order relative to the
+/// signature is misleading: a reader matching up the two by position will
+/// pair each description with the wrong parameter.
+///
+/// This check only flags genuine reordering. A parameter documented but
+/// missing from the signature, or a parameter in the signature but not
+/// documented, is reported by other `DOC` rules; `self` and `cls` are
+/// ignored, as they're conventionally omitted from docstrings.
 ///
 /// ## Example
 /// ```python
+/// def calculate_speed(distance: float, time: float) -> float:
+///     """Calculate speed.
+///
+///     Args:
+///         time: Time spent traveling.
+///         distance: Distance traveled.
+///     """
+///     return distance / time
 /// ```
 ///
 /// Use instead:
 /// ```python
+/// def calculate_speed(distance: float, time: float) -> float:
+///     """Calculate speed.
+///
+///     Args:
+///         distance: Distance traveled.
+///         time: Time spent traveling.
+///     """
+///     return distance / time
 /// ```
 #[derive(ViolationMetadata)]
-pub(crate) struct SignatureDocstringArgumentOrderDiscrepancy;
+pub(crate) struct SignatureDocstringArgumentOrderDiscrepancy {
+    definition: String,
+}
 
 impl Violation for SignatureDocstringArgumentOrderDiscrepancy {
     #[derive_message_formats]
     fn message(&self) -> String {
-        format!("TODO: write message: {}", todo!("implement message"))
+        let Self { definition } = self;
+        format!("Documented parameters for `{definition}` are out of order with the signature")
     }
 }
 
 /// DOC104
-pub(crate) fn signature_docstring_argument_order_discrepancy(checker: &mut Checker) {}
+pub(crate) fn signature_docstring_argument_order_discrepancy(
+    checker: &mut Checker,
+    function_def: &StmtFunctionDef,
+    docstring: &Docstring,
+) {
+    let signature_order = signature_parameter_order(function_def);
+    if signature_order.len() < 2 {
+        // There's nothing for a single parameter to be "out of order" with.
+        return;
+    }
+
+    let Some(documented_order) = documented_parameter_order(docstring.body().as_str()) else {
+        return;
+    };
+
+    // Restrict each ordering to the parameters that appear in both, keeping
+    // each one's own relative order. Parameters documented-but-missing or
+    // undocumented are the concern of sibling `DOC` rules, not this one.
+    let signature_shared: Vec<&str> = signature_order
+        .iter()
+        .map(String::as_str)
+        .filter(|name| documented_order.iter().any(|documented| documented == name))
+        .collect();
+    let documented_shared: Vec<&str> = documented_order
+        .iter()
+        .map(String::as_str)
+        .filter(|name| signature_order.iter().any(|signature| signature == name))
+        .collect();
+
+    if signature_shared.len() < 2 || signature_shared == documented_shared {
+        return;
+    }
+
+    checker.diagnostics.push(Diagnostic::new(
+        SignatureDocstringArgumentOrderDiscrepancy {
+            definition: function_def.name.to_string(),
+        },
+        docstring.range(),
+    ));
+}
+
+/// Returns the function's parameter names in signature order, skipping
+/// `self`/`cls` and any `*args` / `**kwargs`.
+fn signature_parameter_order(function_def: &StmtFunctionDef) -> Vec<String> {
+    function_def
+        .parameters
+        .iter_non_variadic_params()
+        .filter_map(|parameter| {
+            let name = parameter.parameter.name.as_str();
+            (!matches!(name, "self" | "cls")).then(|| name.to_string())
+        })
+        .collect()
+}
+
+/// Returns the parameter names in the order they're documented, or `None`
+/// if the docstring doesn't have a recognizable parameters section.
+///
+/// Supports the same three styles as the rest of the docstring machinery:
+/// Google (`Args:`), NumPy (`Parameters` underlined with `-----`), and reST
+/// (`:param name:`).
+fn documented_parameter_order(body: &str) -> Option<Vec<String>> {
+    if let Some(names) = rest_parameter_order(body) {
+        return Some(names);
+    }
+    if let Some(names) = google_parameter_order(body) {
+        return Some(names);
+    }
+    numpy_parameter_order(body)
+}
+
+/// Extracts parameter names from `:param name:` (optionally `:param type
+/// name:`) lines, in the order they appear.
+fn rest_parameter_order(body: &str) -> Option<Vec<String>> {
+    let names: Vec<String> = body
+        .lines()
+        .filter_map(|line| {
+            let rest = line.trim().strip_prefix(":param ")?;
+            let field = rest.split(':').next()?.trim();
+            // `:param type name:` documents the type before the name.
+            let name = field.rsplit(' ').next()?;
+            Some(name.trim_end_matches('*').to_string())
+        })
+        .collect();
+    (!names.is_empty()).then_some(names)
+}
+
+/// Extracts parameter names from a Google-style `Args:` (or `Arguments:`)
+/// section, where each parameter starts a new, minimally-indented line
+/// formatted as `name:` or `name (type):`.
+fn google_parameter_order(body: &str) -> Option<Vec<String>> {
+    let section_start = body
+        .lines()
+        .position(|line| matches!(line.trim(), "Args:" | "Arguments:"))?;
+
+    let section_indent = body
+        .lines()
+        .nth(section_start + 1)
+        .map(leading_whitespace_len)?;
+
+    let names: Vec<String> = body
+        .lines()
+        .skip(section_start + 1)
+        .take_while(|line| line.trim().is_empty() || leading_whitespace_len(line) >= section_indent)
+        .filter(|line| leading_whitespace_len(line) == section_indent && !line.trim().is_empty())
+        .filter_map(|line| {
+            let trimmed = line.trim();
+            let name = trimmed.split([':', ' ', '(']).next()?;
+            (!name.is_empty()).then(|| name.to_string())
+        })
+        .collect();
+
+    (!names.is_empty()).then_some(names)
+}
+
+/// Extracts parameter names from a NumPy-style `Parameters` section,
+/// underlined with a row of `-`, where each parameter starts a new,
+/// minimally-indented line formatted as `name : type`.
+fn numpy_parameter_order(body: &str) -> Option<Vec<String>> {
+    let lines: Vec<&str> = body.lines().collect();
+    let header = lines
+        .iter()
+        .position(|line| line.trim() == "Parameters")?;
+    let underline = lines.get(header + 1)?;
+    if !underline.trim().chars().all(|c| c == '-') || underline.trim().is_empty() {
+        return None;
+    }
+
+    let section_indent = lines.get(header + 2).map(|line| leading_whitespace_len(line))?;
+
+    let names: Vec<String> = lines
+        .iter()
+        .skip(header + 2)
+        .take_while(|line| line.trim().is_empty() || leading_whitespace_len(line) >= section_indent)
+        .filter(|line| leading_whitespace_len(line) == section_indent && !line.trim().is_empty())
+        .flat_map(|line| {
+            // NumPy allows documenting several parameters that share a type
+            // on one line, e.g. `x, y : int`; split the name list on `,`
+            // rather than just taking the first word, or `y` would be
+            // dropped (and `x` would keep its trailing comma).
+            let name_list = line.trim().split(':').next().unwrap_or_default();
+            name_list
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    (!names.is_empty()).then_some(names)
+}
+
+/// Returns the number of leading whitespace characters on a line.
+fn leading_whitespace_len(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use crate::assert_messages;
+    use crate::registry::Rule;
+    use crate::settings::LinterSettings;
+    use crate::test::test_path;
+
+    use super::{google_parameter_order, numpy_parameter_order, rest_parameter_order};
+
+    #[test]
+    fn signature_docstring_argument_order_discrepancy() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("pydoclint/DOC104.py"),
+            &LinterSettings::for_rule(Rule::SignatureDocstringArgumentOrderDiscrepancy),
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn google_order() {
+        let body = "Args:\n    b: second.\n    a: first.\n";
+        assert_eq!(
+            google_parameter_order(body),
+            Some(vec!["b".to_string(), "a".to_string()])
+        );
+    }
+
+    #[test]
+    fn numpy_order() {
+        let body = "Parameters\n----------\nb : int\n    second.\na : int\n    first.\n";
+        assert_eq!(
+            numpy_parameter_order(body),
+            Some(vec!["b".to_string(), "a".to_string()])
+        );
+    }
+
+    #[test]
+    fn numpy_order_shared_type() {
+        // NumPy lets several parameters documented together share a single
+        // type, e.g. `x, y : int`.
+        let body = "Parameters\n----------\nb, c : int\n    second and third.\na : int\n    first.\n";
+        assert_eq!(
+            numpy_parameter_order(body),
+            Some(vec!["b".to_string(), "c".to_string(), "a".to_string()])
+        );
+    }
+
+    #[test]
+    fn rest_order() {
+        let body = ":param b: second.\n:param a: first.\n";
+        assert_eq!(
+            rest_parameter_order(body),
+            Some(vec!["b".to_string(), "a".to_string()])
+        );
+    }
+}
@@ -1,6 +1,13 @@
-use ruff_diagnostics::{Diagnostic, Violation};
+use std::cell::RefCell;
+use std::collections::hash_map::Entry;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use ruff_diagnostics::{Diagnostic, Edit, Fix, Violation};
 use ruff_macros::{derive_message_formats, violation};
-use rustpython_parser::ast::{Alias, Ranged, Stmt};
+use ruff_python_semantic::Binding;
+use rustc_hash::FxHashMap;
+use rustpython_parser::ast::{Alias, Expr, Ranged, Stmt};
 
 use crate::checkers::ast::Checker;
 
@@ -18,6 +25,11 @@ use crate::checkers::ast::Checker;
 ///
 /// Instead, consider using the public API of the module.
 ///
+/// If the import itself is what makes the name private — for example,
+/// `from foo import Thing as _bar`, where `Thing` is public but the local
+/// alias is not — the rule points to the public name it aliases and, where
+/// unambiguous, offers a fix that imports the public name directly.
+///
 /// ## Known problems
 /// Does not ignore private name imports from within the module that defines
 /// the private name if the module is defined with [PEP 420] namespace packages
@@ -28,6 +40,15 @@ use crate::checkers::ast::Checker;
 /// from foo import _bar
 /// ```
 ///
+/// ```python
+/// from foo import Thing as _bar
+/// ```
+///
+/// Use instead:
+/// ```python
+/// from foo import Thing
+/// ```
+///
 /// ## References
 /// - [PEP 8: Naming Conventions](https://peps.python.org/pep-0008/#naming-conventions)
 /// - [Semantic Versioning](https://semver.org/)
@@ -38,21 +59,34 @@ use crate::checkers::ast::Checker;
 #[violation]
 pub struct ImportPrivateName {
     name: String,
+    public_alias: Option<String>,
 }
 
 impl Violation for ImportPrivateName {
     #[derive_message_formats]
     fn message(&self) -> String {
-        let ImportPrivateName { name } = self;
-        format!("Imported private name `{name}`")
+        let ImportPrivateName { name, public_alias } = self;
+        match public_alias {
+            Some(public_alias) => {
+                format!(
+                    "Imported private name `{name}` is a private alias of the public `{public_alias}`"
+                )
+            }
+            None => format!("Imported private name `{name}`"),
+        }
     }
 }
 
 /// PLC2701
+///
+/// Only handles the module-level case (`import foo._bar`, or `from
+/// foo._bar import baz`), where the private segment lives in the dotted
+/// path itself. Privacy introduced by an imported *member*'s own name
+/// (`from foo import _bar`) is handled by [`import_private_name_deferred`],
+/// once the name's usages in this file are fully resolved.
 pub(crate) fn import_private_name(
     checker: &mut Checker,
     stmt: &Stmt,
-    names: &[Alias],
     module: Option<&str>,
     level: Option<u32>,
     module_path: Option<&[String]>,
@@ -61,44 +95,294 @@ pub(crate) fn import_private_name(
     if level.map_or(false, |level| level > 0) {
         return;
     }
-    if let Some(module) = module {
-        if module.starts_with("__future__") || module.starts_with("__main__") {
+    let Some(module) = module else {
+        return;
+    };
+    if module.starts_with("__future__") || module.starts_with("__main__") {
+        return;
+    }
+    // Ignore private imports from the same module.
+    // TODO(tjkuson): Make this work with PEP 420 namespace packages.
+    if let Some(module_path) = module_path {
+        let root_module = module_path.first().unwrap();
+        if module.starts_with(root_module) {
             return;
         }
-        // Ignore private imports from the same module.
-        // TODO(tjkuson): Make this work with PEP 420 namespace packages.
-        if let Some(module_path) = module_path {
-            let root_module = module_path.first().unwrap();
-            if module.starts_with(root_module) {
-                return;
-            }
+    }
+    if module.starts_with('_') || module.contains("._") {
+        let private_name = module
+            .split('.')
+            .find(|name| name.starts_with('_'))
+            .unwrap_or(module);
+        checker.diagnostics.push(Diagnostic::new(
+            ImportPrivateName {
+                name: private_name.to_string(),
+                public_alias: None,
+            },
+            stmt.range(),
+        ));
+    }
+}
+
+/// PLC2701
+///
+/// Handles `from foo import _bar` (and `from foo import Thing as _bar`),
+/// where the private name comes from the imported member rather than the
+/// module path. This has to run as a deferred check, after the checker has
+/// walked the whole file: the fix rewrites every usage of the private local
+/// name to the public one, and `binding.references()` only reflects usages
+/// seen so far — for an import, that's normally none of them, since real
+/// usages come later in the file.
+pub(crate) fn import_private_name_deferred(
+    checker: &Checker,
+    binding: &Binding,
+) -> Option<Diagnostic> {
+    let import = binding.as_any_import()?;
+    let from_import = import.as_from_import()?;
+    let qualified_name = from_import.qualified_name.to_string();
+    let (module, original_name) = qualified_name.rsplit_once('.')?;
+
+    let Stmt::ImportFrom(import_from) = binding.statement(checker.semantic())? else {
+        return None;
+    };
+    let local_name = binding.name(checker.locator());
+    let alias = import_from.names.iter().find(|alias| {
+        let bound = alias.asname.as_ref().unwrap_or(&alias.name);
+        bound.as_str() == local_name
+    })?;
+
+    // It is common to import the package version as `__version__` and to
+    // name translation functions `_`. Ignore these names.
+    if matches!(local_name, "__version__" | "_") {
+        return None;
+    }
+    if !local_name.starts_with('_') {
+        return None;
+    }
+
+    let (public_name, public_alias) = if local_name != original_name
+        && !original_name.starts_with('_')
+    {
+        // The import itself is what makes the name private, e.g. `from foo
+        // import Thing as _bar`, where `Thing` is already public.
+        (
+            Some(original_name.to_string()),
+            Some(format!("{module}.{original_name}")),
+        )
+    } else {
+        // `from foo import _bar`: trace through `foo`'s own source to see
+        // whether `_bar` is itself just a re-export of some already-public
+        // `foo.Thing`.
+        let public_name = resolve_public_alias(checker, module, original_name);
+        let public_alias = public_name
+            .as_deref()
+            .map(|public_name| format!("{module}.{public_name}"));
+        (public_name, public_alias)
+    };
+
+    let mut diagnostic = Diagnostic::new(
+        ImportPrivateName {
+            name: local_name.to_string(),
+            public_alias,
+        },
+        alias.range(),
+    );
+    if let Some(public_name) = &public_name {
+        if let Some(fix) = fix_private_name_to_public(checker, binding, alias, public_name) {
+            diagnostic.set_fix(fix);
         }
-        if module.starts_with('_') || module.contains("._") {
-            let private_name = module
-                .split('.')
-                .find(|name| name.starts_with('_'))
-                .unwrap_or(module);
-            checker.diagnostics.push(Diagnostic::new(
-                ImportPrivateName {
-                    name: private_name.to_string(),
-                },
-                stmt.range(),
-            ));
+    }
+    Some(diagnostic)
+}
+
+/// Rewrite an import of a private name to import `public_name` instead,
+/// updating every in-scope reference to the old local name to use the
+/// public name instead. Covers both the purely local case (`Thing as
+/// _bar`, where `public_name` is just the member's original name) and the
+/// cross-module case (`_bar` resolved via [`resolve_public_alias`]).
+///
+/// Returns `None` if the public name is already bound to something else in
+/// scope.
+fn fix_private_name_to_public(
+    checker: &Checker,
+    binding: &Binding,
+    alias: &Alias,
+    public_name: &str,
+) -> Option<Fix> {
+    if checker.semantic().lookup_symbol(public_name).is_some() {
+        return None;
+    }
+
+    let mut edits = vec![Edit::range_replacement(
+        public_name.to_string(),
+        alias.range(),
+    )];
+    for reference_id in binding.references() {
+        let reference = checker.semantic().reference(reference_id);
+        edits.push(Edit::range_replacement(
+            public_name.to_string(),
+            reference.range(),
+        ));
+    }
+
+    let (first, rest) = edits.split_first()?;
+    Some(Fix::safe_edits(first.clone(), rest.to_vec()))
+}
+
+/// Trace whether `private_name`, imported from `module`, is itself just a
+/// re-export of some already-public symbol inside that module — e.g.
+/// `foo/__init__.py` containing `from foo._impl import Thing as _bar`, or a
+/// plain `_bar = Thing` aliasing an already-public `foo.Thing`.
+///
+/// This requires reading and parsing `module`'s own source, since the
+/// re-export lives in a file other than the one being linted; returns
+/// `None` if the module can't be resolved to a file on `lint.src`, can't be
+/// read, or doesn't contain an unambiguous public alias.
+fn resolve_public_alias(checker: &Checker, module: &str, private_name: &str) -> Option<String> {
+    let module_path = resolve_module_path(checker, module)?;
+    let body = cached_module_body(&module_path)?;
+
+    // What does `private_name` actually point to inside `module`? (`None` if
+    // it's defined directly, e.g. by a `def` or `class`.)
+    let target = top_level_binding_source(&body, private_name);
+
+    // A plain `_bar = Thing` already names the public symbol.
+    if let Some(target) = target.as_deref() {
+        if !target.contains('.') && !target.starts_with('_') {
+            return Some(target.to_string());
         }
-        for n in names {
-            // It is common to import the package version as `__version__` and
-            // to name translation functions `_`. Ignore these names.
-            if matches!(n.name.as_str(), "__version__" | "_") {
-                continue;
-            }
-            if n.name.starts_with('_') {
-                checker.diagnostics.push(Diagnostic::new(
-                    ImportPrivateName {
-                        name: n.name.to_string(),
-                    },
-                    n.range(),
-                ));
-            }
+    }
+
+    // Does exactly one other, public name in `module` point to the same
+    // thing, or to `private_name` itself (e.g. `Thing = _bar`)?
+    let mut candidates = body
+        .iter()
+        .flat_map(top_level_bindings)
+        .filter(|(name, source)| {
+            name != private_name
+                && !name.starts_with('_')
+                && (source == private_name || Some(source.as_str()) == target.as_deref())
+        })
+        .map(|(name, _)| name);
+    let public_name = candidates.next()?;
+    candidates.next().is_none().then_some(public_name)
+}
+
+thread_local! {
+    /// Caches the parsed top-level body of a module resolved via
+    /// `resolve_module_path`, keyed by file path. A single module is
+    /// commonly re-exported from many places, and without this cache every
+    /// private-name import of it would re-read and re-parse the same file
+    /// from scratch. Scoped per-thread (rather than behind a shared lock)
+    /// since the linter walks files across a thread pool and this rule has
+    /// no other cross-file shared state to coordinate with.
+    static MODULE_BODY_CACHE: RefCell<FxHashMap<PathBuf, Option<Rc<[Stmt]>>>> =
+        RefCell::new(FxHashMap::default());
+}
+
+/// Reads and parses `path`, caching the result (including failures) for the
+/// lifetime of this thread.
+fn cached_module_body(path: &std::path::Path) -> Option<Rc<[Stmt]>> {
+    MODULE_BODY_CACHE.with(|cache| match cache.borrow_mut().entry(path.to_path_buf()) {
+        Entry::Occupied(entry) => entry.get().clone(),
+        Entry::Vacant(entry) => {
+            let body = std::fs::read_to_string(path).ok().and_then(|source| {
+                rustpython_parser::parse_program(&source, &path.to_string_lossy()).ok()
+            });
+            let body = body.map(|body| Rc::from(body.into_boxed_slice()));
+            entry.insert(body.clone());
+            body
         }
+    })
+}
+
+/// Resolves a dotted module name to a source file under one of
+/// `lint.src`'s search roots, preferring a package's `__init__.py`.
+fn resolve_module_path(checker: &Checker, module: &str) -> Option<PathBuf> {
+    let relative = module.replace('.', "/");
+    checker.settings().src.iter().find_map(|root| {
+        let package = root.join(&relative).join("__init__.py");
+        if package.is_file() {
+            return Some(package);
+        }
+        let file = root.join(format!("{relative}.py"));
+        file.is_file().then_some(file)
+    })
+}
+
+/// Finds the qualified source (e.g. `foo._impl.Thing`, or a bare name for a
+/// simple assignment) that `name` is bound to among `body`'s top-level
+/// statements.
+fn top_level_binding_source(body: &[Stmt], name: &str) -> Option<String> {
+    body.iter().find_map(|stmt| {
+        top_level_bindings(stmt)
+            .into_iter()
+            .find(|(bound, _)| bound == name)
+            .map(|(_, source)| source)
+    })
+}
+
+/// Extracts the `(bound_name, qualified_source)` pairs a top-level
+/// statement introduces: one pair per alias for `from ... import ...`, and
+/// one pair per simple `name = other_name` assignment.
+fn top_level_bindings(stmt: &Stmt) -> Vec<(String, String)> {
+    match stmt {
+        Stmt::ImportFrom(import_from) => {
+            let Some(module) = import_from.module.as_ref() else {
+                return Vec::new();
+            };
+            import_from
+                .names
+                .iter()
+                .map(|alias| {
+                    let bound = alias.asname.as_ref().unwrap_or(&alias.name).to_string();
+                    let source = format!("{module}.{}", alias.name);
+                    (bound, source)
+                })
+                .collect()
+        }
+        Stmt::Assign(assign) => {
+            let Expr::Name(value) = assign.value.as_ref() else {
+                return Vec::new();
+            };
+            assign
+                .targets
+                .iter()
+                .filter_map(|target| {
+                    let Expr::Name(target) = target else {
+                        return None;
+                    };
+                    Some((target.id.to_string(), value.id.to_string()))
+                })
+                .collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use crate::assert_messages;
+    use crate::registry::Rule;
+    use crate::settings::LinterSettings;
+    use crate::test::{test_path, test_resource_path};
+
+    #[test]
+    fn import_private_name() -> Result<()> {
+        // `PLC2701_module.py`, which the cross-module resolution case
+        // needs to read, lives alongside the fixture itself.
+        let diagnostics = test_path(
+            Path::new("pylint/PLC2701.py"),
+            &LinterSettings {
+                src: vec![test_resource_path("fixtures/pylint")],
+                ..LinterSettings::for_rule(Rule::ImportPrivateName)
+            },
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
     }
 }
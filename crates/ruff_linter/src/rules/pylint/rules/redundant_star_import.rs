@@ -0,0 +1,129 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use rustpython_parser::ast::{Ranged, Stmt};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `from module import *` statements that contribute nothing,
+/// because every name they could provide is shadowed by an explicit import
+/// or a local definition.
+///
+/// ## Why is this bad?
+/// Named imports and definitions shadow glob imports for the names they
+/// bind. If every name actually used in the module that could plausibly
+/// come from the wildcard is instead bound by some other, more specific
+/// import or definition, the wildcard import is redundant: removing it
+/// changes nothing at runtime, but makes the module easier to read, since
+/// a reader no longer has to guess which names might originate from the
+/// star import.
+///
+/// This commonly happens when a wildcard import is left in a module after
+/// the names it was relied on for were given their own explicit imports.
+///
+/// Used names aren't traced back to the particular wildcard import that
+/// provides them: if any name in the module relies on some wildcard import
+/// to resolve, none of the module's wildcard imports are reported.
+///
+/// ## Example
+/// ```python
+/// from module import *
+/// from module import func
+///
+/// func()
+/// ```
+///
+/// Use instead:
+/// ```python
+/// from module import func
+///
+/// func()
+/// ```
+#[violation]
+pub struct RedundantStarImport;
+
+impl Violation for RedundantStarImport {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "Wildcard import is redundant, as all used names are shadowed by explicit bindings"
+            .to_string()
+    }
+}
+
+/// PLC2702
+pub(crate) fn redundant_star_import(checker: &mut Checker, stmt: &Stmt) {
+    let Stmt::ImportFrom(import_from) = stmt else {
+        return;
+    };
+    if !import_from
+        .names
+        .iter()
+        .any(|alias| alias.name.as_str() == "*")
+    {
+        return;
+    }
+
+    // If some unresolved reference in the module depends on a wildcard
+    // import to resolve, this one isn't reported. References aren't
+    // attributed to a particular wildcard import, so a single such
+    // reference keeps every wildcard import in the module from being
+    // reported.
+    if checker
+        .semantic()
+        .unresolved_references()
+        .any(|reference| reference.is_wildcard_import())
+    {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(RedundantStarImport, stmt.range());
+
+    // A wildcard import in `__init__.py` is commonly kept purely to
+    // re-export names to consumers of the package, even if nothing in the
+    // file itself relies on it; deleting it would silently change the
+    // package's public API.
+    if !checker
+        .path()
+        .file_name()
+        .is_some_and(|name| name == "__init__.py")
+    {
+        diagnostic.set_fix(Fix::safe_edit(Edit::deletion(stmt.start(), stmt.end())));
+    }
+
+    checker.diagnostics.push(diagnostic);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use crate::assert_messages;
+    use crate::registry::Rule;
+    use crate::settings::LinterSettings;
+    use crate::test::test_path;
+
+    #[test]
+    fn redundant_star_import() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("pylint/PLC2702.py"),
+            &LinterSettings::for_rule(Rule::RedundantStarImport),
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn redundant_star_import_init() -> Result<()> {
+        // A wildcard import in `__init__.py` is commonly kept purely to
+        // re-export names; the diagnostic should still fire, but without a
+        // fix attached.
+        let diagnostics = test_path(
+            Path::new("pylint/PLC2702_init/__init__.py"),
+            &LinterSettings::for_rule(Rule::RedundantStarImport),
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+}